@@ -0,0 +1,382 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A generational-index arena (slot map) built on top of [Mapping].
+//!
+//! # Note
+//!
+//! This collection doesn't actually "own" any data.
+//! Instead it is just a simple wrapper around the contract storage facilities.
+
+use ink_primitives::Key as StorageAddressKey;
+use ink_storage_traits::{AutoKey, Packed, Storable, StorableHint, StorageKey};
+
+use crate::{Lazy, Mapping};
+
+/// A generational index into a [StorageSlab].
+///
+/// Unlike a plain `u32` index into a [`StorageVec`](crate::StorageVec), a [Key]
+/// is paired with a generation counter. Once the slot it points to is removed
+/// and recycled for a new value, the old [Key] no longer matches the new
+/// generation and [`StorageSlab::get`]/[`StorageSlab::remove`] will return
+/// `None` for it, rather than silently aliasing the new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Key {
+    index: u32,
+    generation: u32,
+}
+
+/// The contents of a single slot of a [StorageSlab].
+#[derive(Debug, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+enum Entry<V> {
+    /// The slot holds a live value at the given `generation`.
+    Occupied { generation: u32, value: V },
+    /// The slot is part of the free list; `next_free` is the next free slot
+    /// (or `u32::MAX` if this is the last one) and `generation` is the
+    /// generation the slot will have once it is reused.
+    Free { next_free: u32, generation: u32 },
+}
+
+/// The head of a [StorageSlab]: the number of live elements (which also
+/// happens to be the next fresh index to hand out, but only while the free
+/// list is empty) and the head of the free list.
+#[derive(Debug, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+struct Head {
+    len: u32,
+    next_free: u32,
+}
+
+impl Head {
+    const EMPTY_FREE_LIST: u32 = u32::MAX;
+}
+
+impl Default for Head {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            next_free: Self::EMPTY_FREE_LIST,
+        }
+    }
+}
+
+/// A generational-index arena of values (elements) directly on contract storage.
+///
+/// # Important
+///
+/// [StorageSlab] requires its own pre-defined storage key where to store values. By
+/// default, the is automatically calculated using [`AutoKey`](crate::traits::AutoKey)
+/// during compilation. However, anyone can specify a storage key using
+/// [`ManualKey`](crate::traits::ManualKey). Specifying the storage key can be helpful for
+/// upgradeable contracts or you want to be resistant to future changes of storage key
+/// calculation strategy.
+///
+/// # Differences between [`StorageVec`](crate::StorageVec) and [StorageSlab]
+///
+/// [`StorageVec::swap_remove`](crate::StorageVec::swap_remove) invalidates the index of
+/// whichever element used to occupy the last slot, so it is unsuitable whenever contracts
+/// hand out indices as stable handles (e.g. order ids, NFT ids, object pool handles).
+/// [StorageSlab] instead recycles removed slots through a free list and tags each slot with
+/// a generation counter, so a stale [Key] can never silently alias a reinserted value.
+///
+/// # Storage Layout
+///
+/// At given [StorageKey] `K`, the [Head] of the [StorageSlab] (its length and the head of
+/// the free list) is held. Each slot is then stored under a combination of the
+/// [StorageSlab] key `K` and the slot's index, exactly as for [`StorageVec`](crate::StorageVec).
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct StorageSlab<V: Packed, KeyType: StorageKey = AutoKey> {
+    head: Lazy<Head, KeyType>,
+    head_cached: Option<Head>,
+    slots: Mapping<u32, Entry<V>, KeyType>,
+}
+
+impl<V, KeyType> Default for StorageSlab<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, KeyType> Storable for StorageSlab<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: scale::Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: scale::Input>(_input: &mut I) -> Result<Self, scale::Error> {
+        Ok(Default::default())
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        0
+    }
+}
+
+impl<V, Key, InnerKey> StorableHint<Key> for StorageSlab<V, InnerKey>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = StorageSlab<V, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<V, KeyType> StorageKey for StorageSlab<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: StorageAddressKey = KeyType::KEY;
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use crate::traits::StorageLayout;
+    use ink_metadata::layout::{Layout, LayoutKey, RootLayout};
+
+    impl<V, KeyType> StorageLayout for StorageSlab<V, KeyType>
+    where
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + scale_info::TypeInfo + 'static,
+    {
+        fn layout(_: &StorageAddressKey) -> Layout {
+            Layout::Root(RootLayout::new::<Self, _>(
+                LayoutKey::from(&KeyType::KEY),
+                <V as StorageLayout>::layout(&KeyType::KEY),
+            ))
+        }
+    }
+};
+
+impl<V, KeyType> StorageSlab<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Creates a new empty `StorageSlab`.
+    pub const fn new() -> Self {
+        Self {
+            head: Lazy::new(),
+            head_cached: None,
+            slots: Mapping::new(),
+        }
+    }
+
+    fn head(&self) -> Head {
+        self.head_cached
+            .unwrap_or_else(|| self.head.get().unwrap_or_default())
+    }
+
+    fn set_head(&mut self, new_head: Head) {
+        self.head.set(&new_head);
+        self.head_cached = Some(new_head);
+    }
+
+    /// Returns the number of live elements in the slab.
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.head().len
+    }
+
+    /// Returns `true` if the slab contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts `value` into the slab and returns a [Key] that can be used to
+    /// [`get`](Self::get) or [`remove`](Self::remove) it again.
+    ///
+    /// Reuses a slot freed by a previous [`remove`](Self::remove) if one is
+    /// available, otherwise allocates a fresh slot.
+    ///
+    /// # Panics
+    ///
+    /// * If the slab is at capacity (max. of 2 ^ 32 slots ever allocated).
+    /// * If the value overgrows the static buffer size.
+    pub fn insert(&mut self, value: V) -> Key {
+        let mut head = self.head();
+
+        let (index, generation) = if head.next_free == Head::EMPTY_FREE_LIST {
+            let index = head.len;
+            (index, 0)
+        } else {
+            let index = head.next_free;
+            let Entry::Free {
+                next_free,
+                generation,
+            } = self.slots.get(index).unwrap()
+            else {
+                panic!("free list corrupted: slot is not free")
+            };
+            head.next_free = next_free;
+            (index, generation)
+        };
+
+        head.len = head.len.checked_add(1).unwrap();
+        self.set_head(head);
+
+        self.slots
+            .insert(index, &Entry::Occupied { generation, value });
+
+        Key { index, generation }
+    }
+
+    /// Returns the value associated with `key`, or `None` if `key` is stale
+    /// (its slot was removed and possibly reused) or was never occupied.
+    ///
+    /// # Panics
+    ///
+    /// * If encoding the element exceeds the static buffer size.
+    pub fn get(&self, key: Key) -> Option<V> {
+        match self.slots.get(key.index)? {
+            Entry::Occupied { generation, value } if generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Removes the value associated with `key` and returns it, recycling the
+    /// slot for a future [`insert`](Self::insert).
+    ///
+    /// Returns `None` if `key` is stale or was never occupied.
+    ///
+    /// # Panics
+    ///
+    /// * If encoding or decoding the element exceeds the static buffer size.
+    pub fn remove(&mut self, key: Key) -> Option<V> {
+        let value = match self.slots.get(key.index)? {
+            Entry::Occupied { generation, value } if generation == key.generation => value,
+            _ => return None,
+        };
+
+        let mut head = self.head();
+        self.slots.insert(
+            key.index,
+            &Entry::<V>::Free {
+                next_free: head.next_free,
+                generation: key.generation.wrapping_add(1),
+            },
+        );
+        head.next_free = key.index;
+        head.len = head.len.checked_sub(1).unwrap();
+        self.set_head(head);
+
+        Some(value)
+    }
+}
+
+impl<V, KeyType> ::core::fmt::Debug for StorageSlab<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("StorageSlab")
+            .field("key", &KeyType::KEY)
+            .field("head", &self.head)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::ManualKey;
+
+    #[test]
+    fn default_values() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let slab: StorageSlab<String> = StorageSlab::new();
+
+            assert_eq!(slab.len(), 0);
+            assert!(slab.is_empty());
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn insert_get_remove_work() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut slab: StorageSlab<String> = StorageSlab::new();
+
+            let key = slab.insert("test".to_string());
+            assert_eq!(slab.len(), 1);
+            assert_eq!(slab.get(key), Some("test".to_string()));
+
+            assert_eq!(slab.remove(key), Some("test".to_string()));
+            assert_eq!(slab.len(), 0);
+            assert_eq!(slab.get(key), None);
+            assert_eq!(slab.remove(key), None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn stale_key_does_not_alias_reinserted_value() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut slab: StorageSlab<u8> = StorageSlab::new();
+
+            let first = slab.insert(1);
+            slab.remove(first).unwrap();
+
+            let second = slab.insert(2);
+            assert_eq!(second.index, first.index);
+            assert_ne!(second.generation, first.generation);
+
+            assert_eq!(slab.get(first), None);
+            assert_eq!(slab.get(second), Some(2));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn storage_keys_are_correct() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            const BASE: u32 = 123;
+            let mut slab: StorageSlab<u8, ManualKey<BASE>> = StorageSlab::new();
+
+            let key = slab.insert(127);
+
+            let actual_head = ink_env::get_contract_storage::<_, Head>(&BASE);
+            assert!(matches!(
+                actual_head,
+                Ok(Some(Head {
+                    len: 1,
+                    next_free: Head::EMPTY_FREE_LIST
+                }))
+            ));
+            assert_eq!(key.index, 0);
+            assert_eq!(key.generation, 0);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+}
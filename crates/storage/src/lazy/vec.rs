@@ -59,9 +59,10 @@ use crate::{Lazy, Mapping};
 ///
 /// # Caveats
 ///
-/// Iteration is not providided. [StorageVec] is expected to be used to
-/// store a lot or large values where iterating through elements would be
-/// rather inefficient anyways.
+/// [StorageVec] is expected to be used to store a lot or large values where
+/// iterating through elements would be rather inefficient anyways. [`iter`](StorageVec::iter)
+/// is provided for convenience, but loads one element per storage read, so it
+/// should be used with that cost in mind.
 ///
 /// The decision whether to use `Vec<T>` or [StorageVec] can be seen as an
 /// optimization problem with several factors:
@@ -210,6 +211,45 @@ where
         assert!(self.elements.insert(slot, value).is_none());
     }
 
+    /// Appends all elements yielded by `iter` to the back of the vector, in
+    /// order.
+    ///
+    /// The cached length is only read once and updated once at the end,
+    /// rather than once per pushed element.
+    ///
+    /// # Panics
+    ///
+    /// * If the vector is at capacity (max. of 2 ^ 32 elements).
+    /// * If a value overgrows the static buffer size.
+    /// * If there was already a value at the current index.
+    pub fn extend<T, I>(&mut self, iter: I)
+    where
+        T: Storable + scale::EncodeLike<V>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut slot = self.len();
+        for value in iter {
+            assert!(self.elements.insert(slot, &value).is_none());
+            slot = slot.checked_add(1).unwrap();
+        }
+        self.set_len(slot);
+    }
+
+    /// Removes all elements from the vector, freeing the storage deposit of
+    /// each element.
+    ///
+    /// # Note
+    ///
+    /// Simply resetting the cached length to zero would leave the per-element
+    /// storage (and its deposit) dangling, so each element is explicitly
+    /// removed.
+    pub fn clear(&mut self) {
+        for index in 0..self.len() {
+            self.elements.remove(index);
+        }
+        self.set_len(0);
+    }
+
     /// Pops the last element from the vector and returns it.
     //
     /// Returns `None` if the vector is empty.
@@ -248,6 +288,169 @@ where
 
         let _ = self.elements.insert(index, value);
     }
+
+    /// Removes the element at `index` and returns it, replacing it with the
+    /// last element of the vector.
+    ///
+    /// This does not preserve ordering, but is O(1), touching at most the
+    /// removed slot and the last slot.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// * If decoding or encoding an element exceeds the static buffer size.
+    pub fn swap_remove(&mut self, index: u32) -> Option<V> {
+        let last = self.len().checked_sub(1)?;
+        if index > last {
+            return None;
+        }
+
+        self.set_len(last);
+
+        if index == last {
+            return self.elements.take(last);
+        }
+
+        let value = self.elements.take(index);
+        let last_value = self.elements.take(last).unwrap();
+        self.elements.insert(index, &last_value);
+        value
+    }
+
+    /// Returns an iterator over the elements of the vector.
+    ///
+    /// # Note
+    ///
+    /// Each call to `next`/`next_back` performs a storage read of a single
+    /// element, so iterating does not load the whole vector at once. This
+    /// avoids the static buffer limit that iterating a `Vec<T>` would hit.
+    pub fn iter(&self) -> Iter<V, KeyType> {
+        Iter::new(self)
+    }
+
+    /// Binary searches this vector, assumed to be sorted, with a comparator
+    /// function.
+    ///
+    /// The comparator function should return an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` than the target
+    /// the caller is searching for. It is only called on elements that are
+    /// actually loaded from storage, i.e. at most `O(log(self.len()))` times.
+    ///
+    /// See [`binary_search`](Self::binary_search) for details on the result.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<u32, u32>
+    where
+        F: FnMut(&V) -> core::cmp::Ordering,
+    {
+        use core::cmp::Ordering::*;
+
+        let mut lo = 0;
+        let mut hi = self.len();
+
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let value = self.get(mid).unwrap();
+            match f(&value) {
+                Less => lo = mid + 1,
+                Greater => hi = mid,
+                Equal => return Ok(mid),
+            }
+        }
+
+        Err(lo)
+    }
+}
+
+impl<V, KeyType> StorageVec<V, KeyType>
+where
+    V: Packed + Ord,
+    KeyType: StorageKey,
+{
+    /// Binary searches this vector, assumed to be sorted, for `target`.
+    ///
+    /// If the vector is not sorted, the returned result is unspecified and
+    /// meaningless.
+    ///
+    /// Costs `O(log(self.len()))` storage reads, each loading a single
+    /// element, rather than decoding the whole vector as a `Vec<T>` would.
+    ///
+    /// Returns `Ok(index)` of a matching element if found, otherwise
+    /// `Err(insertion_point)` of where `target` could be inserted to keep
+    /// the vector sorted, matching the semantics of
+    /// [`[T]::binary_search`](https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search).
+    pub fn binary_search(&self, target: &V) -> Result<u32, u32> {
+        self.binary_search_by(|value| value.cmp(target))
+    }
+}
+
+/// Iterator over the elements of a [StorageVec].
+pub struct Iter<'a, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    vec: &'a StorageVec<V, KeyType>,
+    front: u32,
+    back: u32,
+}
+
+impl<'a, V, KeyType> Iter<'a, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    pub(crate) fn new(vec: &'a StorageVec<V, KeyType>) -> Self {
+        Self {
+            vec,
+            front: 0,
+            back: vec.len(),
+        }
+    }
+}
+
+impl<'a, V, KeyType> Iterator for Iter<'a, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let value = self.vec.get(self.front);
+        self.front += 1;
+        value
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, V, KeyType> DoubleEndedIterator for Iter<'a, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.vec.get(self.back)
+    }
+}
+
+impl<'a, V, KeyType> ExactSizeIterator for Iter<'a, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
 }
 
 impl<V, KeyType> ::core::fmt::Debug for StorageVec<V, KeyType>
@@ -353,6 +556,138 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn swap_remove_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = StorageVec::new();
+
+            array.push(&1);
+            array.push(&2);
+            array.push(&3);
+
+            assert_eq!(array.swap_remove(0), Some(1));
+            assert_eq!(array.len(), 2);
+            assert_eq!(array.get(0), Some(3));
+            assert_eq!(array.get(1), Some(2));
+
+            assert_eq!(array.swap_remove(1), Some(2));
+            assert_eq!(array.len(), 1);
+            assert_eq!(array.get(0), Some(3));
+
+            assert_eq!(array.swap_remove(0), Some(3));
+            assert_eq!(array.len(), 0);
+
+            assert_eq!(array.swap_remove(0), None);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn iter_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<u8> = StorageVec::new();
+            array.push(&1);
+            array.push(&2);
+            array.push(&3);
+
+            let mut iter = array.iter();
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.next(), Some(1));
+            assert_eq!(iter.next_back(), Some(3));
+            assert_eq!(iter.next(), Some(2));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            assert_eq!(array.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn iter_on_empty_vec_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let array: StorageVec<u8> = StorageVec::new();
+
+            let mut iter = array.iter();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            assert_eq!(array.iter().rev().collect::<Vec<_>>(), Vec::<u8>::new());
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn binary_search_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let mut array: StorageVec<i32> = StorageVec::new();
+            for value in [1, 3, 3, 5, 8, 13] {
+                array.push(&value);
+            }
+
+            assert_eq!(array.binary_search(&5), Ok(3));
+            assert!(matches!(array.binary_search(&3), Ok(1) | Ok(2)));
+            assert_eq!(array.binary_search(&0), Err(0));
+            assert_eq!(array.binary_search(&4), Err(3));
+            assert_eq!(array.binary_search(&13), Ok(5));
+            assert_eq!(array.binary_search(&100), Err(6));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn binary_search_on_empty_vec_works() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            let array: StorageVec<i32, ManualKey<999>> = StorageVec::new();
+            assert_eq!(array.binary_search(&0), Err(0));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn extend_and_clear_work() {
+        ink_env::test::run_test::<ink_env::DefaultEnvironment, _>(|_| {
+            const BASE: u32 = 456;
+            let mut array: StorageVec<u8, ManualKey<BASE>> = StorageVec::new();
+
+            array.push(&1);
+            array.extend(vec![2, 3, 4]);
+            assert_eq!(array.len(), 4);
+            assert_eq!(array.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+            array.clear();
+            assert_eq!(array.len(), 0);
+            assert!(array.is_empty());
+            assert_eq!(array.get(0), None);
+            assert_eq!(array.pop(), None);
+
+            // `clear` must actually remove the per-element storage, not just reset
+            // the cached length, otherwise the storage deposit would dangle.
+            for index in 0..4u32 {
+                let actual_value = ink_env::get_contract_storage::<_, u8>(&(BASE, index));
+                assert_eq!(actual_value, Ok(None));
+            }
+
+            array.extend(vec![5, 6]);
+            assert_eq!(array.len(), 2);
+            assert_eq!(array.get(0), Some(5));
+
+            Ok(())
+        })
+        .unwrap()
+    }
+
     #[test]
     #[should_panic]
     fn set_panics_on_oob() {